@@ -2,20 +2,75 @@ mod error;
 mod project;
 
 use crate::error::Result;
-use clap::{Parser, Subcommand};
-use project::Project;
+use clap::{Args, Parser, Subcommand};
+use project::{Level, Project};
 
 #[derive(Parser, Debug)]
 #[clap(version)]
 #[command()]
-struct Args {
+struct Cli {
+    /// Print what would change without writing or committing anything.
+    #[clap(long = "dry-run", global = true)]
+    dry_run: bool,
+
     #[command(subcommand)]
     cmd: Commands,
 }
 
+#[derive(Args, Debug, Clone)]
+struct BumpArgs {
+    /// If supplied with -m or --message config option, cargo will use it as a commit message when creating a version commit.
+    /// If the message config contains %s then that will be replaced with the resulting version number. For example:
+    ///
+    /// Cargo verison patch -m "Upgrade to %s for reasons"
+    ///
+    #[clap(short, long)]
+    message: Option<String>,
+
+    /// Tag the commit when using the cargo verison command. Setting this to false results in no commit being made at all.
+    #[clap(long = "git-tag-version")]
+    add_git_tag: Option<bool>,
+
+    /// Stage a pre-release identifier, e.g. "alpha". Re-running with the same
+    /// identifier increments its counter (1.2.3-alpha.1 -> 1.2.3-alpha.2)
+    /// instead of bumping the core version again.
+    #[clap(long = "pre-release")]
+    pre_release: Option<String>,
+
+    /// Set an explicit build-metadata component, e.g. "exp.sha.5114f85".
+    #[clap(long = "build")]
+    build: Option<String>,
+
+    /// Derive the current version from the most recent git tag (`git describe
+    /// --long --abbrev=7`) instead of Cargo.toml before bumping.
+    #[clap(long = "from-git")]
+    from_git: Option<bool>,
+
+    /// Prefix applied to the tag name, e.g. "v" to tag "v1.2.3".
+    #[clap(long = "tag-prefix", default_value = "")]
+    tag_prefix: String,
+
+    /// Create a lightweight tag instead of an annotated one.
+    #[clap(long = "lightweight")]
+    lightweight: Option<bool>,
+
+    /// Update the workspace version
+    #[clap(long = "workspace")]
+    workspace: Option<bool>,
+
+    /// Project directory. Defaults to current_dir.
+    #[clap(long = "directory")]
+    directory: Option<String>,
+}
+
 #[derive(Subcommand, Debug, Clone)]
 enum Commands {
     Current {
+        /// Derive the current version from the most recent git tag (`git
+        /// describe --long --abbrev=7`) instead of Cargo.toml.
+        #[clap(long = "from-git")]
+        from_git: Option<bool>,
+
         /// Update the workspace version
         #[clap(long = "workspace")]
         workspace: Option<bool>,
@@ -24,60 +79,108 @@ enum Commands {
         #[clap(long = "directory")]
         directory: Option<String>,
     },
-    Patch {
-        /// If supplied with -m or --message config option, cargo will use it as a commit message when creating a version commit.
-        /// If the message config contains %s then that will be replaced with the resulting version number. For example:
-        ///
-        /// Cargo verison patch -m "Upgrade to %s for reasons"
-        ///
-        #[clap(short, long)]
-        message: Option<String>,
-
-        /// Tag the commit when using the cargo verison command. Setting this to false results in no commit being made at all.
-        #[clap(long = "git-tag-version")]
-        add_git_tag: Option<bool>,
-
-        /// Update the workspace version
-        #[clap(long = "workspace")]
-        workspace: Option<bool>,
+    /// Bump the major version, e.g. 1.2.3 -> 2.0.0
+    Major(BumpArgs),
+    /// Bump the minor version, e.g. 1.2.3 -> 1.3.0
+    Minor(BumpArgs),
+    /// Bump the patch version, e.g. 1.2.3 -> 1.2.4
+    Patch(BumpArgs),
+    /// Set the version to an explicit semver string, e.g. 1.2.3
+    Set {
+        /// The semver version to set.
+        version: String,
 
-        /// Project directory. Defaults to current_dir.
-        #[clap(long = "directory")]
-        directory: Option<String>,
+        #[clap(flatten)]
+        bump: BumpArgs,
     },
+    /// Release a staged pre-release version, e.g. 1.3.0-alpha.2 -> 1.3.0
+    Promote(BumpArgs),
+}
+
+fn finish(project: Project, new_version: String, args: &BumpArgs) -> Result<()> {
+    project.write()?;
+    project.cargo_update()?;
+    let add_git_tag = args.add_git_tag.unwrap_or(true);
+
+    if add_git_tag {
+        let lightweight = args.lightweight.unwrap_or(false);
+        project.commit(args.message.clone(), &args.tag_prefix, lightweight)?;
+    }
+
+    println!("{}", new_version);
+    Ok(())
+}
+
+fn reject_pre_release_and_build(args: &BumpArgs, command: &str) -> Result<()> {
+    if args.pre_release.is_some() || args.build.is_some() {
+        return Err(format!("--pre-release and --build are not supported by `{}`", command).into());
+    }
+    Ok(())
+}
+
+fn reject_from_git(args: &BumpArgs, command: &str) -> Result<()> {
+    if args.from_git.unwrap_or(false) {
+        return Err(format!("--from-git is not supported by `{}`", command).into());
+    }
+    Ok(())
+}
+
+fn bump(level: Level, args: BumpArgs, dry_run: bool) -> Result<()> {
+    let workspace = args.workspace.unwrap_or(false);
+    let mut project = Project::create_with_options(workspace, args.directory.clone(), dry_run)?;
+
+    if args.from_git.unwrap_or(false) {
+        project.use_git_version()?;
+    }
+
+    let new_version = project.bump(level, args.pre_release.as_deref(), args.build.as_deref())?;
+    finish(project, new_version, &args)
 }
 
 pub fn main() -> Result<()> {
-    let args = Args::parse();
+    let args = Cli::parse();
+    let dry_run = args.dry_run;
 
     match args.cmd {
         Commands::Current {
+            from_git,
             workspace,
             directory,
         } => {
             let workspace = workspace.unwrap_or(false);
-            let project = Project::create(workspace, directory)?;
+            let mut project = Project::create_with_options(workspace, directory, dry_run)?;
+
+            if from_git.unwrap_or(false) {
+                project.use_git_version()?;
+            }
 
             println!("{}", project.get_current_version())
         }
-        Commands::Patch {
-            message,
-            add_git_tag,
-            workspace,
-            directory,
-        } => {
-            let workspace = workspace.unwrap_or(false);
-            let mut project = Project::create(workspace, directory)?;
-            let new_version = project.next_patch()?;
-            project.write()?;
-            project.cargo_update()?;
-            let add_git_tag = add_git_tag.unwrap_or(true);
-
-            if add_git_tag {
-                project.commit(message)?;
+        Commands::Major(args) => bump(Level::Major, args, dry_run)?,
+        Commands::Minor(args) => bump(Level::Minor, args, dry_run)?,
+        Commands::Patch(args) => bump(Level::Patch, args, dry_run)?,
+        Commands::Set { version, bump: args } => {
+            reject_pre_release_and_build(&args, "set")?;
+            reject_from_git(&args, "set")?;
+            let workspace = args.workspace.unwrap_or(false);
+            let mut project =
+                Project::create_with_options(workspace, args.directory.clone(), dry_run)?;
+            project.set_version(&version)?;
+            let new_version = project.get_current_version();
+            finish(project, new_version, &args)?;
+        }
+        Commands::Promote(args) => {
+            reject_pre_release_and_build(&args, "promote")?;
+            let workspace = args.workspace.unwrap_or(false);
+            let mut project =
+                Project::create_with_options(workspace, args.directory.clone(), dry_run)?;
+
+            if args.from_git.unwrap_or(false) {
+                project.use_git_version()?;
             }
 
-            println!("{}", new_version);
+            let new_version = project.promote()?;
+            finish(project, new_version, &args)?;
         }
     };
     Ok(())
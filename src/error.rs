@@ -15,12 +15,12 @@ pub enum VerisonError {
 impl fmt::Display for VerisonError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            VerisonError::Other(err) => write!(f, "{}", err.to_string()),
-            VerisonError::IO(err) => write!(f, "{}", err.to_string()),
-            VerisonError::Toml(err) => write!(f, "{}", err.to_string()),
-            VerisonError::TomlEdit(err) => write!(f, "{}", err.to_string()),
-            VerisonError::SemVer(err) => write!(f, "{}", err.to_string()),
-            VerisonError::Git(err) => write!(f, "{}", err.to_string()),
+            VerisonError::Other(err) => write!(f, "{}", err),
+            VerisonError::IO(err) => write!(f, "{}", err),
+            VerisonError::Toml(err) => write!(f, "{}", err),
+            VerisonError::TomlEdit(err) => write!(f, "{}", err),
+            VerisonError::SemVer(err) => write!(f, "{}", err),
+            VerisonError::Git(err) => write!(f, "{}", err),
         }
     }
 }
@@ -72,3 +72,30 @@ impl From<git2::Error> for VerisonError {
         VerisonError::Git(err)
     }
 }
+
+impl From<anyhow::Error> for VerisonError {
+    fn from(err: anyhow::Error) -> Self {
+        let err = match err.downcast::<semver::Error>() {
+            Ok(err) => return VerisonError::SemVer(err),
+            Err(err) => err,
+        };
+        let err = match err.downcast::<git2::Error>() {
+            Ok(err) => return VerisonError::Git(err),
+            Err(err) => err,
+        };
+        let err = match err.downcast::<toml::de::Error>() {
+            Ok(err) => return VerisonError::Toml(err),
+            Err(err) => err,
+        };
+        let err = match err.downcast::<toml_edit::TomlError>() {
+            Ok(err) => return VerisonError::TomlEdit(err),
+            Err(err) => err,
+        };
+        let err = match err.downcast::<std::io::Error>() {
+            Ok(err) => return VerisonError::IO(err),
+            Err(err) => err,
+        };
+
+        VerisonError::Other(err.to_string())
+    }
+}
@@ -1,4 +1,5 @@
-use git2::{Commit, Repository};
+use git2::{Commit, DescribeFormatOptions, DescribeOptions, Repository};
+use glob::glob;
 use serde::Deserialize;
 use std::env::current_dir;
 use std::fs::{read_to_string, OpenOptions};
@@ -19,6 +20,8 @@ struct Config {
 #[derive(Deserialize, Clone, Debug)]
 struct Workspace {
     pub package: Package,
+    #[serde(default)]
+    pub members: Vec<String>,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -26,15 +29,28 @@ struct WorkspaceConfig {
     pub workspace: Workspace,
 }
 
+/// The component of a semver version to increment when bumping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    Major,
+    Minor,
+    Patch,
+}
+
 pub struct Project {
     workspace: bool,
     semver: semver::Version,
     repository: Result<Repository, git2::Error>,
     directory: Option<String>,
+    dry_run: bool,
 }
 
 impl Project {
-    pub fn create(workspace: bool, directory: Option<String>) -> anyhow::Result<Self> {
+    pub fn create_with_options(
+        workspace: bool,
+        directory: Option<String>,
+        dry_run: bool,
+    ) -> anyhow::Result<Self> {
         let mut path = match directory.as_ref() {
             None => current_dir()?,
             Some(dir) => PathBuf::from(dir),
@@ -60,6 +76,7 @@ impl Project {
             semver,
             repository,
             directory,
+            dry_run,
         })
     }
 
@@ -68,18 +85,87 @@ impl Project {
         Ok(())
     }
 
-    pub fn next_patch(&mut self) -> anyhow::Result<String> {
+    fn bump_core(current: &semver::Version, level: Level) -> semver::Version {
+        match level {
+            Level::Major => semver::Version::new(current.major + 1, 0, 0),
+            Level::Minor => semver::Version::new(current.major, current.minor + 1, 0),
+            Level::Patch => semver::Version::new(current.major, current.minor, current.patch + 1),
+        }
+    }
+
+    /// Returns the trailing counter `N` of `current`'s pre-release if it is of the
+    /// form `<id>.<N>`, so a matching `--pre-release` bump knows to increment it
+    /// in place instead of cutting a fresh core bump.
+    fn matching_prerelease(current: &semver::Version, id: &str) -> Option<u64> {
+        current
+            .pre
+            .as_str()
+            .strip_prefix(id)?
+            .strip_prefix('.')?
+            .parse::<u64>()
+            .ok()
+    }
+
+    /// Bumps `level`, optionally staging a pre-release and/or build-metadata
+    /// component on the result.
+    ///
+    /// When `pre_release` names an identifier already present on the current
+    /// version as `<id>.<N>`, only the counter is incremented and the core
+    /// version is left untouched (e.g. `1.2.3-alpha.1` -> `1.2.3-alpha.2`).
+    /// Otherwise the requested `level` is bumped first and `<id>.1` is
+    /// appended (e.g. `1.2.3` -> `1.3.0-alpha.1`).
+    pub fn bump(
+        &mut self,
+        level: Level,
+        pre_release: Option<&str>,
+        build: Option<&str>,
+    ) -> anyhow::Result<String> {
+        let current = self.semver.clone();
+
+        let mut next = match pre_release {
+            Some(id) => match Self::matching_prerelease(&current, id) {
+                Some(n) => {
+                    let mut next = current.clone();
+                    next.pre = semver::Prerelease::new(&format!("{}.{}", id, n + 1))?;
+                    next
+                }
+                None => {
+                    let mut next = Self::bump_core(&current, level);
+                    next.pre = semver::Prerelease::new(&format!("{}.1", id))?;
+                    next
+                }
+            },
+            None => Self::bump_core(&current, level),
+        };
+
+        if let Some(build) = build {
+            next.build = semver::BuildMetadata::new(build)?;
+        }
+
+        self.set_version(next.to_string().as_str())?;
+        Ok(self.get_current_version())
+    }
+
+    /// Strips any pre-release and build-metadata component, releasing the
+    /// staged pre-release version as-is (e.g. `1.3.0-alpha.2` -> `1.3.0`).
+    pub fn promote(&mut self) -> anyhow::Result<String> {
         let mut next = self.semver.clone();
-        next.patch += 1;
+        next.pre = semver::Prerelease::EMPTY;
+        next.build = semver::BuildMetadata::EMPTY;
         self.set_version(next.to_string().as_str())?;
-        return Ok(self.get_current_version());
+        Ok(self.get_current_version())
     }
 
     pub fn get_current_version(&self) -> String {
-        return self.semver.to_string();
+        self.semver.to_string()
     }
 
     pub fn cargo_update(&self) -> anyhow::Result<String> {
+        if self.dry_run {
+            println!("[dry-run] would run `cargo generate-lockfile` to refresh Cargo.lock");
+            return Ok(String::new());
+        }
+
         std::process::Command::new("cargo")
             .arg("generate-lockfile")
             .arg("--verbose")
@@ -99,24 +185,130 @@ impl Project {
         }
     }
 
+    fn workspace_root(&self) -> anyhow::Result<PathBuf> {
+        match self.directory.as_ref() {
+            None => Ok(current_dir()?),
+            Some(dir) => Ok(PathBuf::from(dir)),
+        }
+    }
+
     pub fn write(&self) -> anyhow::Result<()> {
-        let mut path = match self.directory.as_ref() {
-            None => current_dir()?,
-            Some(dir) => PathBuf::from(dir),
-        };
+        let mut path = self.workspace_root()?;
 
         path.push("Cargo.toml");
         let file = read_to_string(&path)?;
         let mut document = file.parse::<DocumentMut>()?;
         self.update_version(&mut document);
-        let mut file = OpenOptions::new().write(true).truncate(true).open(&path)?;
-        file.write_all(document.to_string().as_bytes())?;
+        let rendered = document.to_string();
+
+        if self.dry_run {
+            print_manifest_diff(&path, &file, &rendered);
+        } else {
+            let mut file = OpenOptions::new().write(true).truncate(true).open(&path)?;
+            file.write_all(rendered.as_bytes())?;
+        }
+
+        if self.workspace {
+            self.update_members()?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the `[workspace] members` globs in the root manifest to the
+    /// directories of the member crates.
+    fn discover_members(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let root = self.workspace_root()?;
+        let contents = read_to_string(root.join("Cargo.toml"))?;
+        let config: WorkspaceConfig = toml::from_str(&contents)?;
+
+        let mut members = Vec::new();
+        for pattern in &config.workspace.members {
+            for entry in glob(&root.join(pattern).to_string_lossy())? {
+                let member_dir = entry?;
+                if member_dir.join("Cargo.toml").is_file() {
+                    members.push(member_dir);
+                }
+            }
+        }
+
+        Ok(members)
+    }
+
+    /// Propagates the current version to every workspace member whose
+    /// `package.version` is a literal string (members inheriting it via
+    /// `version.workspace = true` pick up the new `[workspace.package]`
+    /// version on their own and are left untouched), and rewrites any
+    /// intra-workspace `path = ".."` dependency on a bumped member to
+    /// require the new version too.
+    fn update_members(&self) -> anyhow::Result<()> {
+        let members = self.discover_members()?;
+        let version = self.get_current_version();
+
+        let member_names: Vec<String> = members
+            .iter()
+            .filter_map(|dir| {
+                let contents = read_to_string(dir.join("Cargo.toml")).ok()?;
+                let document = contents.parse::<DocumentMut>().ok()?;
+                document["package"]["name"].as_str().map(str::to_owned)
+            })
+            .collect();
+
+        const DEPENDENCY_TABLES: [&str; 3] =
+            ["dependencies", "dev-dependencies", "build-dependencies"];
+
+        for member_dir in &members {
+            let manifest_path = member_dir.join("Cargo.toml");
+            let contents = read_to_string(&manifest_path)?;
+            let mut document = contents.parse::<DocumentMut>()?;
+            let mut changed = false;
+
+            if document["package"]["version"].as_str().is_some() {
+                document["package"]["version"] = toml_edit::value(version.clone());
+                changed = true;
+            }
+
+            for table_key in DEPENDENCY_TABLES {
+                let Some(deps) = document
+                    .get_mut(table_key)
+                    .and_then(|item| item.as_table_like_mut())
+                else {
+                    continue;
+                };
+
+                for (dep_name, dep_item) in deps.iter_mut() {
+                    let is_sibling_path_dep = member_names.iter().any(|name| name == dep_name.get())
+                        && dep_item
+                            .as_table_like()
+                            .is_some_and(|dep| dep.contains_key("path"));
+
+                    if is_sibling_path_dep {
+                        dep_item["version"] = toml_edit::value(version.clone());
+                        changed = true;
+                    }
+                }
+            }
+
+            if changed {
+                let rendered = document.to_string();
+
+                if self.dry_run {
+                    print_manifest_diff(&manifest_path, &contents, &rendered);
+                } else {
+                    let mut file = OpenOptions::new()
+                        .write(true)
+                        .truncate(true)
+                        .open(&manifest_path)?;
+                    file.write_all(rendered.as_bytes())?;
+                }
+            }
+        }
 
         Ok(())
     }
 
     #[inline(always)]
-    fn find_last_commit(repo: &Repository) -> anyhow::Result<Commit> {
+    fn find_last_commit(repo: &Repository) -> anyhow::Result<Commit<'_>> {
         let obj = repo.head()?.resolve()?.peel(git2::ObjectType::Commit)?;
         let commit = obj
             .into_commit()
@@ -124,24 +316,82 @@ impl Project {
         Ok(commit)
     }
 
-    pub fn commit(&self, message: Option<String>) -> anyhow::Result<()> {
+    fn repository(&self) -> anyhow::Result<&Repository> {
+        match self.repository.as_ref() {
+            Ok(repo) => Ok(repo),
+            Err(git_error) => Err(git2::Error::new(
+                git_error.code(),
+                git_error.class(),
+                git_error.message(),
+            )
+            .into()),
+        }
+    }
+
+    /// Derives the current version from the most recent git tag via
+    /// `git describe --long --abbrev=7`, rewriting its `-<count>-g<sha>`
+    /// suffix into a pre-release component (e.g. `v1.2.3-4-gabcdef` ->
+    /// `1.2.3-r4.gabcdef`) instead of relying on a possibly stale
+    /// `Cargo.toml`.
+    pub fn version_from_git(&self) -> anyhow::Result<semver::Version> {
+        let repo = self.repository()?;
+        let description = repo.describe(DescribeOptions::new().describe_tags())?.format(Some(
+            DescribeFormatOptions::new()
+                .always_use_long_format(true)
+                .abbreviated_size(7),
+        ))?;
+
+        Self::parse_git_describe(&description)
+    }
+
+    fn parse_git_describe(description: &str) -> anyhow::Result<semver::Version> {
+        let trimmed = description.strip_prefix('v').unwrap_or(description);
+
+        let (head, sha) = trimmed
+            .rsplit_once('-')
+            .ok_or_else(|| anyhow::anyhow!("unexpected `git describe` output: {}", description))?;
+        let (version, count) = head
+            .rsplit_once('-')
+            .ok_or_else(|| anyhow::anyhow!("unexpected `git describe` output: {}", description))?;
+        let sha = sha.strip_prefix('g').unwrap_or(sha);
+
+        let mut version = semver::Version::parse(version)?;
+        version.pre = semver::Prerelease::new(&format!("r{}.g{}", count, sha))?;
+        Ok(version)
+    }
+
+    /// Replaces the in-memory version with the one derived from `git describe`.
+    pub fn use_git_version(&mut self) -> anyhow::Result<()> {
+        self.semver = self.version_from_git()?;
+        Ok(())
+    }
+
+    fn tag_name(tag_prefix: &str, version: &str) -> String {
+        format!("{}{}", tag_prefix, version)
+    }
+
+    /// Commits the staged manifest changes and tags the result.
+    ///
+    /// `tag_prefix` is prepended to the version for the tag name (e.g. `v` to
+    /// tag `v1.2.3`). Unless `lightweight` is set, the tag is created as an
+    /// annotated tag carrying `message`, with the repository's configured
+    /// signature recorded as its tagger. The tag is not GPG-signed.
+    pub fn commit(&self, message: Option<String>, tag_prefix: &str, lightweight: bool) -> anyhow::Result<()> {
         let version = self.get_current_version();
         let commit = match message {
             Some(msg) => msg.replace("%s", &version),
             None => version.clone(),
         };
 
-        let repo = match self.repository.as_ref() {
-            Ok(repo) => repo,
-            Err(git_error) => {
-                return Err(git2::Error::new(
-                    git_error.code(),
-                    git_error.class(),
-                    git_error.message(),
-                )
-                .into());
-            }
-        };
+        if self.dry_run {
+            let tag_name = Self::tag_name(tag_prefix, &version);
+            let tag_kind = if lightweight { "lightweight" } else { "annotated" };
+            println!("[dry-run] would commit with message: {}", commit);
+            println!("[dry-run] would create {} tag: {}", tag_kind, tag_name);
+            return Ok(());
+        }
+
+        let repo = self.repository()?;
 
         let mut index = repo.index()?;
 
@@ -149,10 +399,21 @@ impl Project {
         let cargo_lock = "Cargo.lock";
         index.add_path(Path::new(&cargo_manifest))?;
         index.add_path(Path::new(&cargo_lock))?;
+
+        if self.workspace {
+            let root = self.workspace_root()?;
+            for member_dir in self.discover_members()? {
+                let member_manifest = member_dir.join("Cargo.toml");
+                if let Ok(relative) = member_manifest.strip_prefix(&root) {
+                    index.add_path(relative)?;
+                }
+            }
+        }
+
         index.write()?;
 
         let oid = index.write_tree()?;
-        let parent_commit = Self::find_last_commit(&repo)?;
+        let parent_commit = Self::find_last_commit(repo)?;
         let tree = repo.find_tree(oid)?;
         let signature = repo.signature()?;
 
@@ -166,101 +427,178 @@ impl Project {
         )?;
 
         let object = repo.find_object(new_oid, Some(git2::ObjectType::Commit))?;
-        repo.tag_lightweight(version.clone().as_str(), &object, false)?;
+        let tag_name = Self::tag_name(tag_prefix, &version);
+
+        if lightweight {
+            repo.tag_lightweight(&tag_name, &object, false)?;
+        } else {
+            repo.tag(&tag_name, &object, &signature, &commit, false)?;
+        }
 
         Ok(())
     }
 }
 
+/// Prints the whole manifest with `-`/`+` markers on the lines a manifest
+/// write would change, without touching disk.
+fn print_manifest_diff(path: &Path, before: &str, after: &str) {
+    if before == after {
+        return;
+    }
+
+    println!("--- {}", path.display());
+    println!("+++ {}", path.display());
+
+    for change in similar::TextDiff::from_lines(before, after).iter_all_changes() {
+        let sign = match change.tag() {
+            similar::ChangeTag::Delete => "-",
+            similar::ChangeTag::Insert => "+",
+            similar::ChangeTag::Equal => " ",
+        };
+        print!("{}{}", sign, change);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::project::Project;
+    use crate::project::{Level, Project};
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A private copy of a fixture directory tree, so tests that write to the
+    /// fixture don't race with other tests reading or writing the same files.
+    /// Removed from disk when dropped.
+    struct TempFixture {
+        path: PathBuf,
+    }
+
+    impl TempFixture {
+        fn new(source: &str) -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+            let mut path = std::env::temp_dir();
+            path.push(format!("cargo-verison-test-{}-{}", std::process::id(), id));
+            copy_dir_recursive(Path::new(source), &path).unwrap();
+
+            TempFixture { path }
+        }
+
+        fn directory(&self) -> Option<String> {
+            Some(self.path.to_string_lossy().into_owned())
+        }
+    }
+
+    impl Drop for TempFixture {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn copy_dir_recursive(source: &Path, destination: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(destination)?;
+
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            let destination_path = destination.join(entry.file_name());
+
+            if entry.file_type()?.is_dir() {
+                copy_dir_recursive(&entry.path(), &destination_path)?;
+            } else {
+                std::fs::copy(entry.path(), &destination_path)?;
+            }
+        }
+
+        Ok(())
+    }
 
     #[test]
     fn it_can_create_a_project() {
-        let project = Project::create(false, None).unwrap();
-        assert_eq!(project.workspace, false);
+        let project = Project::create_with_options(false, None, false).unwrap();
+        assert!(!project.workspace);
         assert!(project.repository.is_ok());
         assert!(!project.semver.to_string().is_empty());
     }
 
     #[test]
     fn it_cannot_create_a_project() {
-        let project = Project::create(false, Some(String::from("/tmp")));
+        let project = Project::create_with_options(false, Some(String::from("/tmp")), false);
         assert!(project.is_err());
     }
 
     #[test]
     fn it_can_create_a_project_from_path() {
-        let project = Project::create(false, Some(String::from("tests/standalone")));
+        let project = Project::create_with_options(false, Some(String::from("tests/standalone")), false);
         assert!(project.is_ok());
-        assert_eq!(project.as_ref().unwrap().workspace, false);
+        assert!(!project.as_ref().unwrap().workspace);
         assert!(!project.as_ref().unwrap().semver.to_string().is_empty());
     }
 
     #[test]
     fn it_can_read_version_from_a_path() {
-        let project = Project::create(false, Some(String::from("tests/standalone")));
+        let project = Project::create_with_options(false, Some(String::from("tests/standalone")), false);
         assert!(project.is_ok());
         assert_eq!(project.unwrap().semver.to_string(), "1.2.3");
     }
 
     #[test]
     fn it_can_fail_to_create_a_project_from_a_workspace() {
-        let project = Project::create(false, Some(String::from("tests/workspace")));
+        let project = Project::create_with_options(false, Some(String::from("tests/workspace")), false);
         assert!(project.is_err());
     }
 
     #[test]
     fn it_can_fail_to_create_a_project_from_a_workspace_member() {
-        let project = Project::create(
+        let project = Project::create_with_options(
             false,
             Some(String::from("tests/workspace/workspace-member")),
+            false,
         );
         assert!(project.is_err());
     }
 
     #[test]
     fn it_can_create_a_project_from_a_workspace() {
-        let project = Project::create(true, Some(String::from("tests/workspace")));
+        let project = Project::create_with_options(true, Some(String::from("tests/workspace")), false);
         assert!(project.is_ok());
-        assert_eq!(project.as_ref().unwrap().workspace, true);
+        assert!(project.as_ref().unwrap().workspace);
         assert!(!project.as_ref().unwrap().semver.to_string().is_empty());
     }
 
     #[test]
     fn it_cannot_create_a_workspace_project_from_standalone() {
-        let project = Project::create(true, Some(String::from("tests/standalone")));
+        let project = Project::create_with_options(true, Some(String::from("tests/standalone")), false);
         assert!(project.is_err());
     }
 
     #[test]
     fn it_can_read_version_a_project_from_a_workspace() {
-        let project = Project::create(true, Some(String::from("tests/workspace")));
+        let project = Project::create_with_options(true, Some(String::from("tests/workspace")), false);
         assert!(project.is_ok());
         assert_eq!(project.as_ref().unwrap().semver.to_string(), "3.2.1");
     }
 
     #[test]
     fn it_can_read_and_calculate_version_a_project_from_a_workspace() {
-        let mut project = Project::create(true, Some(String::from("tests/workspace")));
+        let mut project = Project::create_with_options(true, Some(String::from("tests/workspace")), false);
         assert!(project.is_ok());
         assert_eq!(project.as_ref().unwrap().semver.to_string(), "3.2.1");
         assert_eq!(project.as_ref().unwrap().get_current_version(), "3.2.1");
-        assert_eq!(project.as_mut().unwrap().next_patch().unwrap(), "3.2.2");
+        assert_eq!(project.as_mut().unwrap().bump(Level::Patch, None, None).unwrap(), "3.2.2");
         assert_eq!(project.as_ref().unwrap().get_current_version(), "3.2.2");
     }
 
     #[test]
     fn it_can_read_and_calculate_and_write_a_project() {
-        let mut project = Project::create(false, Some(String::from("tests/standalone")));
+        let fixture = TempFixture::new("tests/standalone");
+        let mut project = Project::create_with_options(false, fixture.directory(), false);
         assert!(project.is_ok());
         assert_eq!(project.as_ref().unwrap().semver.to_string(), "1.2.3");
         assert_eq!(project.as_ref().unwrap().get_current_version(), "1.2.3");
-        assert_eq!(project.as_mut().unwrap().next_patch().unwrap(), "1.2.4");
+        assert_eq!(project.as_mut().unwrap().bump(Level::Patch, None, None).unwrap(), "1.2.4");
         assert_eq!(project.as_ref().unwrap().get_current_version(), "1.2.4");
         project.as_mut().unwrap().write().unwrap();
-        let mut project2 = Project::create(false, Some(String::from("tests/standalone")));
+        let mut project2 = Project::create_with_options(false, fixture.directory(), false);
         assert_eq!(project2.as_ref().unwrap().get_current_version(), "1.2.4");
         project2.as_mut().unwrap().set_version("1.2.3").unwrap();
         project2.as_mut().unwrap().write().unwrap();
@@ -269,17 +607,183 @@ mod tests {
 
     #[test]
     fn it_can_read_and_calculate_and_write_a_workspace_project() {
-        let mut project = Project::create(true, Some(String::from("tests/workspace")));
+        let fixture = TempFixture::new("tests/workspace");
+        let mut project = Project::create_with_options(true, fixture.directory(), false);
         assert!(project.is_ok());
         assert_eq!(project.as_ref().unwrap().semver.to_string(), "3.2.1");
         assert_eq!(project.as_ref().unwrap().get_current_version(), "3.2.1");
-        assert_eq!(project.as_mut().unwrap().next_patch().unwrap(), "3.2.2");
+        assert_eq!(project.as_mut().unwrap().bump(Level::Patch, None, None).unwrap(), "3.2.2");
         assert_eq!(project.as_ref().unwrap().get_current_version(), "3.2.2");
         project.as_mut().unwrap().write().unwrap();
-        let mut project2 = Project::create(true, Some(String::from("tests/workspace")));
+        let mut project2 = Project::create_with_options(true, fixture.directory(), false);
         assert_eq!(project2.as_ref().unwrap().get_current_version(), "3.2.2");
         project2.as_mut().unwrap().set_version("3.2.1").unwrap();
         project2.as_mut().unwrap().write().unwrap();
         assert_eq!(project2.as_ref().unwrap().get_current_version(), "3.2.1");
     }
+
+    #[test]
+    fn it_can_bump_major_minor_and_patch() {
+        let mut major = Project::create_with_options(false, Some(String::from("tests/standalone")), false).unwrap();
+        assert_eq!(major.bump(Level::Major, None, None).unwrap(), "2.0.0");
+
+        let mut minor = Project::create_with_options(false, Some(String::from("tests/standalone")), false).unwrap();
+        assert_eq!(minor.bump(Level::Minor, None, None).unwrap(), "1.3.0");
+
+        let mut patch = Project::create_with_options(false, Some(String::from("tests/standalone")), false).unwrap();
+        assert_eq!(patch.bump(Level::Patch, None, None).unwrap(), "1.2.4");
+    }
+
+    #[test]
+    fn it_can_stage_a_new_pre_release() {
+        let mut project = Project::create_with_options(false, Some(String::from("tests/standalone")), false).unwrap();
+        let next = project.bump(Level::Minor, Some("alpha"), None).unwrap();
+        assert_eq!(next, "1.3.0-alpha.1");
+    }
+
+    #[test]
+    fn it_can_increment_an_existing_pre_release() {
+        let mut project = Project::create_with_options(false, Some(String::from("tests/standalone")), false).unwrap();
+        project.set_version("1.3.0-alpha.1").unwrap();
+        let next = project.bump(Level::Minor, Some("alpha"), None).unwrap();
+        assert_eq!(next, "1.3.0-alpha.2");
+    }
+
+    #[test]
+    fn it_can_stage_build_metadata() {
+        let mut project = Project::create_with_options(false, Some(String::from("tests/standalone")), false).unwrap();
+        let next = project.bump(Level::Patch, None, Some("sha.abcdef")).unwrap();
+        assert_eq!(next, "1.2.4+sha.abcdef");
+    }
+
+    #[test]
+    fn it_can_promote_a_pre_release() {
+        let mut project = Project::create_with_options(false, Some(String::from("tests/standalone")), false).unwrap();
+        project.set_version("1.3.0-alpha.2").unwrap();
+        assert_eq!(project.promote().unwrap(), "1.3.0");
+    }
+
+    #[test]
+    fn it_can_parse_a_long_git_describe_string() {
+        let version = Project::parse_git_describe("v1.2.3-4-gabcdef").unwrap();
+        assert_eq!(version.to_string(), "1.2.3-r4.gabcdef");
+    }
+
+    #[test]
+    fn it_can_parse_a_git_describe_string_without_a_v_prefix() {
+        let version = Project::parse_git_describe("1.2.3-0-gabcdef").unwrap();
+        assert_eq!(version.to_string(), "1.2.3-r0.gabcdef");
+    }
+
+    #[test]
+    fn it_cannot_parse_a_malformed_git_describe_string() {
+        assert!(Project::parse_git_describe("not-a-describe-string").is_err());
+    }
+
+    #[test]
+    fn it_can_derive_the_version_from_a_real_git_repository() {
+        let fixture = TempFixture::new("tests/standalone");
+        let repo = git2::Repository::init(&fixture.path).unwrap();
+        let signature = git2::Signature::now("test", "test@example.com").unwrap();
+
+        let commit = |repo: &git2::Repository, message: &str| {
+            let mut index = repo.index().unwrap();
+            index.add_path(Path::new("Cargo.toml")).unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+                .unwrap();
+        };
+
+        commit(&repo, "initial commit");
+        let tagged = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.tag("v1.2.3", tagged.as_object(), &signature, "v1.2.3", false)
+            .unwrap();
+        commit(&repo, "a change since the tag");
+
+        let project = Project::create_with_options(false, fixture.directory(), false).unwrap();
+        let version = project.version_from_git().unwrap();
+        assert_eq!(version.major, 1);
+        assert_eq!(version.minor, 2);
+        assert_eq!(version.patch, 3);
+        assert!(version.pre.as_str().starts_with("r1.g"));
+    }
+
+    #[test]
+    fn it_can_build_tag_names_with_a_prefix() {
+        assert_eq!(Project::tag_name("v", "1.2.3"), "v1.2.3");
+        assert_eq!(Project::tag_name("", "1.2.3"), "1.2.3");
+    }
+
+    #[test]
+    fn it_can_commit_in_dry_run_mode_without_a_real_repository() {
+        let fixture = TempFixture::new("tests/standalone");
+        let project =
+            Project::create_with_options(false, fixture.directory(), true).unwrap();
+
+        // Dry-run short-circuits before touching the repository, so this must
+        // succeed whether or not `self.repository` resolved to a real repo.
+        let result = project.commit(Some("release %s".to_owned()), "v", true);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_can_discover_workspace_members() {
+        let project = Project::create_with_options(true, Some(String::from("tests/workspace")), false).unwrap();
+        let mut names: Vec<String> = project
+            .discover_members()
+            .unwrap()
+            .iter()
+            .map(|dir| dir.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec![
+                "workspace-member",
+                "workspace-member-consumer",
+                "workspace-member-literal",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_can_propagate_version_to_workspace_members() {
+        let fixture = TempFixture::new("tests/workspace");
+        let mut project = Project::create_with_options(true, fixture.directory(), false).unwrap();
+        project.set_version("3.2.2").unwrap();
+        project.write().unwrap();
+
+        let inherited =
+            std::fs::read_to_string(fixture.path.join("workspace-member/Cargo.toml")).unwrap();
+        assert!(inherited.contains("version.workspace = true"));
+
+        let literal = std::fs::read_to_string(
+            fixture.path.join("workspace-member-literal/Cargo.toml"),
+        )
+        .unwrap();
+        assert!(literal.contains("version = \"3.2.2\""));
+
+        let consumer = std::fs::read_to_string(
+            fixture.path.join("workspace-member-consumer/Cargo.toml"),
+        )
+        .unwrap();
+        assert!(consumer.contains("version = \"3.2.2\""));
+        assert!(consumer.contains("path = \"../workspace-member-literal\""));
+    }
+
+    #[test]
+    fn it_does_not_write_in_dry_run_mode() {
+        let mut project =
+            Project::create_with_options(false, Some(String::from("tests/standalone")), true)
+                .unwrap();
+        let before = std::fs::read_to_string("tests/standalone/Cargo.toml").unwrap();
+        project.set_version("9.9.9").unwrap();
+        project.write().unwrap();
+        let after = std::fs::read_to_string("tests/standalone/Cargo.toml").unwrap();
+        assert_eq!(before, after);
+    }
 }